@@ -0,0 +1,241 @@
+use quick_xml::{events::Event, reader::Reader, writer::Writer};
+use tokio::io::AsyncBufRead;
+
+use crate::{Error, Expect};
+
+/// Asynchronous counterpart of [`crate::Expect`], for XML streamed from a
+/// [`tokio::io::AsyncBufRead`] source (e.g. a socket or a file opened via
+/// `tokio::fs`).
+///
+/// Provides async versions of [`crate::Expect`]'s `expect_element`,
+/// `expect_empty`, `expect_eof` and `expect_text`, reading via
+/// [`quick_xml::Reader::read_event_into_async()`] so that awaiting more
+/// input never blocks the executor. The `expect_any` and `expect_many`
+/// combinators have no async version. Unlike the synchronous
+/// `expect_element`, `expect_empty` and `expect_text`, which leave a
+/// non-matching event unread, these consume the event they read (for
+/// `expect_text`, the event that ends the text run is consumed too). This
+/// trait requires the `async` cargo feature.
+///
+/// # Examples
+///
+/// ``` rust
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), xmhell::Error> {
+/// use xmhell::{Expect, quick_xml::reader::Reader};
+///
+/// let mut reader = Reader::from_reader("<root><leaf/></root>".as_bytes());
+/// _ = reader.trim_text(true);
+///
+/// // Called via the fully-qualified path, rather than `use xmhell::AsyncExpect`,
+/// // so that the method-dot calls to the synchronous `Expect` below (on the
+/// // same `Reader<&[u8]>` type) stay unambiguous.
+/// xmhell::AsyncExpect::expect_element(&mut reader, "root")
+///     .await?
+///     .read_inner(|reader| Box::pin(async move {
+///         reader.expect_empty("leaf")?;
+///         Ok(())
+///     }))
+///     .await?;
+/// xmhell::AsyncExpect::expect_eof(&mut reader).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait AsyncExpect<R> {
+    /// Async counterpart of [`crate::Expect::expect_element()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Expect::expect_element()`].
+    fn expect_element<'b>(
+        &'b mut self,
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<AsyncElementReader<'b, R>, Error>>
+    where
+        R: 'b;
+
+    /// Async counterpart of [`crate::Expect::expect_empty()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Expect::expect_empty()`].
+    fn expect_empty(&mut self, name: &str) -> impl std::future::Future<Output = Result<(), Error>>;
+
+    /// Async counterpart of [`crate::Expect::expect_eof()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Expect::expect_eof()`].
+    fn expect_eof(&mut self) -> impl std::future::Future<Output = Result<(), Error>>;
+
+    /// Async counterpart of [`crate::Expect::expect_text()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::Expect::expect_text()`].
+    fn expect_text(&mut self) -> impl std::future::Future<Output = Result<String, Error>>;
+}
+
+impl<R> AsyncExpect<R> for Reader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    async fn expect_element<'b>(&'b mut self, name: &str) -> Result<AsyncElementReader<'b, R>, Error>
+    where
+        R: 'b,
+    {
+        log::debug!("expecting element <{name}>");
+        let mut buf = Vec::new();
+        match self.read_event_into_async(&mut buf).await?.into_owned() {
+            Event::Start(tag) if tag.name().as_ref() == name.as_bytes() => {
+                log::debug!("found element <{name}>, buffering contents");
+                let span = buffer_inner(self, tag.name().as_ref().to_vec()).await?;
+                Ok(AsyncElementReader {
+                    _parent: self,
+                    span,
+                })
+            }
+            Event::Eof => Err(Error::Eof),
+            event => Err(Error::unexpected_event(event)),
+        }
+    }
+
+    async fn expect_empty(&mut self, name: &str) -> Result<(), Error> {
+        log::debug!("expecting element <{name}/>");
+        let mut buf = Vec::new();
+        match self.read_event_into_async(&mut buf).await? {
+            Event::Empty(tag) if tag.name().as_ref() == name.as_bytes() => Ok(()),
+            Event::Eof => Err(Error::Eof),
+            event => Err(Error::unexpected_event(event.into_owned())),
+        }
+    }
+
+    async fn expect_eof(&mut self) -> Result<(), Error> {
+        log::debug!("expecting end-of-file");
+        let mut buf = Vec::new();
+        match self.read_event_into_async(&mut buf).await? {
+            Event::Eof => Ok(()),
+            event => Err(Error::unexpected_event(event.into_owned())),
+        }
+    }
+
+    async fn expect_text(&mut self) -> Result<String, Error> {
+        log::debug!("expecting text node");
+        // Coalesce consecutive `Text`/`CData` events into a single owned
+        // string, mirroring the sync `Expect::expect_text()`.
+        let mut content: Option<String> = None;
+        loop {
+            let mut buf = Vec::new();
+            match self.read_event_into_async(&mut buf).await? {
+                Event::Text(txt) => append(&mut content, &txt.unescape()?),
+                Event::CData(cdata) => {
+                    append(&mut content, &self.decoder().decode(cdata.as_ref())?);
+                }
+                Event::Eof if content.is_none() => return Err(Error::Eof),
+                event if content.is_none() => {
+                    return Err(Error::unexpected_event(event.into_owned()))
+                }
+                _ => break,
+            }
+        }
+        Ok(content.unwrap_or_default())
+    }
+}
+
+/// Append `chunk` to `content`, coalescing consecutive `Text`/`CData` runs
+/// into a single owned string.
+fn append(content: &mut Option<String>, chunk: &str) {
+    *content = Some(content.take().map_or_else(
+        || chunk.to_string(),
+        |mut existing| {
+            existing.push_str(chunk);
+            existing
+        },
+    ));
+}
+
+/// Buffer the serialized contents of an already-matched `<name>` start-tag,
+/// up to (but not including) its matching end-tag, tracking nesting depth so
+/// that nested elements sharing `name` don't terminate the span early.
+async fn buffer_inner<R>(reader: &mut Reader<R>, name: Vec<u8>) -> Result<String, Error>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut writer = Writer::new(Vec::new());
+    let mut depth = 0u32;
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        let event = reader.read_event_into_async(&mut buf).await?;
+        match &event {
+            Event::Start(tag) if tag.name().as_ref() == name => depth += 1,
+            Event::End(tag) if tag.name().as_ref() == name => {
+                if depth == 0 {
+                    break;
+                }
+                depth -= 1;
+            }
+            Event::Eof => return Err(Error::Eof),
+            _ => {}
+        }
+        writer
+            .write_event(event)
+            .map_err(|err| Error::Inner(Box::new(err)))?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|err| Error::Inner(Box::new(err)))
+}
+
+/// An object providing access to the buffered inner content of a non-leaf
+/// XML node, returned by [`AsyncExpect::expect_element()`].
+///
+/// Unlike the synchronous [`crate::ElementReader`], the inner content here
+/// has already been fully read off the stream and re-serialized, so
+/// [`AsyncElementReader::read_inner()`] hands the closure a plain,
+/// synchronous [`quick_xml::Reader`] over that buffer. The closure is still
+/// `async` so it may perform further asynchronous work (e.g. awaiting other
+/// I/O) while processing the matched element.
+pub struct AsyncElementReader<'b, R> {
+    _parent: &'b mut Reader<R>,
+    span: String,
+}
+
+impl<R> std::fmt::Debug for AsyncElementReader<'_, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncElementReader")
+            .field("span", &self.span)
+            .finish()
+    }
+}
+
+impl<R> AsyncElementReader<'_, R> {
+    /// Consume the buffered contents of `self` using a synchronous
+    /// [`quick_xml::Reader`], via an asynchronous closure.
+    ///
+    /// `f` returns a boxed, borrowed future (rather than a bare `impl
+    /// Future`) because the reader it's handed only lives as long as the
+    /// call: a plain generic `Fut` can't express that the future's lifetime
+    /// is tied to the `&mut Reader` argument's, for every possible call.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the closure `f` returns an error, or if the
+    /// [`quick_xml::Reader`] is not fully consumed by `f`.
+    pub async fn read_inner<F, T>(self, mut f: F) -> Result<T, Error>
+    where
+        F: for<'r> FnMut(
+            &'r mut Reader<&[u8]>,
+        ) -> std::pin::Pin<
+            Box<
+                dyn std::future::Future<
+                        Output = Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>,
+                    > + 'r,
+            >,
+        >,
+    {
+        let mut reader = Reader::from_str(&self.span);
+        _ = reader.trim_text(true);
+        let result = f(&mut reader).await?;
+        Expect::expect_eof(&mut reader)?;
+        Ok(result)
+    }
+}
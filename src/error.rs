@@ -12,6 +12,8 @@ pub enum Error {
     UnexpectedEvent(Event<'static>),
     /// An error was returned while processing the inner content of an XML node.
     Inner(Box<dyn std::error::Error + Send + Sync + 'static>),
+    /// A required attribute was not present on a matched start-tag.
+    MissingAttribute(String),
     /// End-of-file while reading input.
     Eof,
 }
@@ -28,6 +30,7 @@ impl fmt::Display for Error {
             Self::Reader(err) => write!(f, "XML read error: {err}"),
             Self::UnexpectedEvent(event) => write!(f, "unexpected XML event: {event:?}"),
             Self::Inner(err) => write!(f, "Error while reading inner content: {err}"),
+            Self::MissingAttribute(key) => write!(f, "missing required attribute '{key}'"),
             Self::Eof => write!(f, "End-of-file while reading inner content"),
         }
     }
@@ -38,7 +41,7 @@ impl std::error::Error for Error {
         match self {
             Self::Reader(err) => Some(err),
             Self::Inner(err) => Some(err.as_ref()),
-            Self::UnexpectedEvent(_) | Self::Eof => None,
+            Self::UnexpectedEvent(_) | Self::MissingAttribute(_) | Self::Eof => None,
         }
     }
 }
@@ -1,13 +1,34 @@
 use core::fmt;
 use std::borrow::Cow;
 
-use quick_xml::{events::Event, reader::Reader};
+use quick_xml::{
+    events::{BytesStart, Event},
+    name::ResolveResult,
+    reader::{NsReader, Reader},
+};
 
 use crate::Error;
 
+/// Decode the attributes of a matched start-tag into owned key/value pairs.
+///
+/// Keys are decoded lossily via [`String::from_utf8_lossy()`] (attribute
+/// names are almost always ASCII); values are unescaped. Both are returned
+/// as owned `String`s.
+fn collect_attributes(tag: &BytesStart<'_>) -> Result<Vec<(String, String)>, Error> {
+    tag.attributes()
+        .map(|attr| {
+            let attr = attr.map_err(quick_xml::Error::from)?;
+            let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+            let value = attr.unescape_value()?.into_owned();
+            Ok((key, value))
+        })
+        .collect()
+}
+
 mod sealed {
     pub trait Sealed {}
     impl Sealed for quick_xml::Reader<&[u8]> {}
+    impl Sealed for quick_xml::reader::NsReader<&[u8]> {}
 }
 use self::sealed::Sealed;
 
@@ -16,11 +37,10 @@ use self::sealed::Sealed;
 /// # Examples
 ///
 /// ``` rust
-/// # use xmhell::{Error, Expect, quick_xml::{events::Event, Reader}};
+/// # use xmhell::{Error, Expect, quick_xml::Reader};
 /// let input = r#"
 ///     <root>
 ///         <ball>red</ball>
-///         <bat/>
 ///         <ball>blue</ball>
 ///         <ball>green</ball>
 ///     </root>
@@ -29,20 +49,10 @@ use self::sealed::Sealed;
 /// let mut reader = Reader::from_str(input);
 /// _ = reader.trim_text(true);
 ///
-/// let mut balls = Vec::new();
-///
-/// reader.expect_element("root")?.read_inner(|reader| loop {
-///     match reader.expect_element("ball") {
-///         Ok(inner) => {
-///             inner.read_inner(|reader| {
-///                 balls.push(reader.expect_text()?.into_owned());
-///                 Ok(())
-///             })?;
-///         }
-///         Err(Error::Eof) => break Ok(()),
-///         Err(Error::UnexpectedEvent(_)) => continue,
-///         Err(err) => break Err(err.into()),
-///     }
+/// let balls = reader.expect_element("root")?.read_inner(|reader| {
+///     Ok(reader.expect_many("ball", |ball| {
+///         ball.read_inner(|reader| Ok(reader.expect_text()?.into_owned()))
+///     })?)
 /// })?;
 /// reader.expect_eof()?;
 ///
@@ -129,17 +139,23 @@ pub trait Expect<'a>: Sealed {
     /// ```
     fn expect_eof(&mut self) -> Result<(), Error>;
 
-    /// Attempt to match and consume a text node.
+    /// Attempt to match and consume a run of text and/or `CDATA` content.
     ///
-    /// On success a [`Cow<'a, str>`][Cow] is returned with the un-escaped text
-    /// of the node.
+    /// Consecutive [`Event::Text`] and [`Event::CData`] events are
+    /// coalesced into a single string, so mixed content such as
+    /// `see <![CDATA[<x>]]> here` is returned whole rather than truncated
+    /// at the first chunk. `Text` content is un-escaped; `CDATA` content is
+    /// taken verbatim. On success a [`Cow<'a, str>`][Cow] is returned; it
+    /// borrows from the input only when a single `Text` chunk needing no
+    /// un-escaping was matched, and is owned otherwise (including for any
+    /// `CDATA` content).
     ///
     /// # Errors
     ///
-    /// An [`Error::Eof`] is returned if `self` reaches the end of it's input.
-    /// This is useful to signal a containing loop to `break`.
-    /// Otherwise, an [`Error::UnexpectedEvent`] is returned if the next
-    /// [`Event`] encountered is not a text node.
+    /// An [`Error::Eof`] is returned if `self` reaches the end of it's input
+    /// before any text or `CDATA` is matched. This is useful to signal a
+    /// containing loop to `break`. Otherwise, an [`Error::UnexpectedEvent`]
+    /// is returned if the next [`Event`] encountered is neither.
     ///
     /// An [`Error::Reader`] is returned if an error is encountered while trying
     /// to read from `self`.
@@ -154,24 +170,109 @@ pub trait Expect<'a>: Sealed {
     ///         .read_inner(|reader| Ok(reader.expect_text()?.into_owned()))?,
     ///     "This is > than that"
     /// );
+    ///
+    /// assert_eq!(
+    ///     Reader::from_str("<note>see <![CDATA[<x>]]> here</note>")
+    ///         .expect_element("note")?
+    ///         .read_inner(|reader| Ok(reader.expect_text()?.into_owned()))?,
+    ///     "see <x> here"
+    /// );
     /// # Ok::<(), Error>(())
     /// ```
     fn expect_text(&mut self) -> Result<Cow<'a, str>, Error>;
+
+    /// Attempt to match and consume a span `<name>...</name>` whose `name`
+    /// is one of `names`.
+    ///
+    /// On success the matched name (borrowed from `names`) is returned
+    /// alongside an [`ElementReader`] for the matched element's contents.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::Eof`] is returned if `self` reaches the end of it's input.
+    /// This is useful to signal a containing loop to `break`.
+    /// Otherwise, an [`Error::UnexpectedEvent`] is returned if the next
+    /// [`Event`] encountered is not a start-tag for one of `names`.
+    ///
+    /// An [`Error::Reader`] is returned if an error is encountered while trying
+    /// to read from `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use xmhell::{Error, Expect, quick_xml::Reader};
+    /// let mut reader = Reader::from_str("<bar></bar>");
+    /// let (matched, _) = reader.expect_any(&["foo", "bar"])?;
+    /// assert_eq!(matched, "bar");
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn expect_any<'n>(
+        &mut self,
+        names: &[&'n str],
+    ) -> Result<(&'n str, ElementReader<'a, '_>), Error>;
+
+    /// Repeatedly match and consume `<name>...</name>` elements, invoking
+    /// `f` on each matched [`ElementReader`], stopping
+    /// cleanly as soon as the next event is not a(nother) `name` element
+    /// (or `self` reaches end-of-file).
+    ///
+    /// This is the declarative counterpart of hand-rolling
+    /// `loop { match expect_element(name) { ... } }` for repeated siblings.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `f` returns an error, or if an error is
+    /// encountered while trying to read from `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use xmhell::{Error, Expect, quick_xml::Reader};
+    /// let input = r#"
+    ///     <root>
+    ///         <ball>red</ball>
+    ///         <ball>blue</ball>
+    ///         <ball>green</ball>
+    ///     </root>
+    /// "#;
+    ///
+    /// let mut reader = Reader::from_str(input);
+    /// _ = reader.trim_text(true);
+    ///
+    /// let balls = reader.expect_element("root")?.read_inner(|reader| {
+    ///     Ok(reader.expect_many("ball", |ball| {
+    ///         ball.read_inner(|reader| Ok(reader.expect_text()?.into_owned()))
+    ///     })?)
+    /// })?;
+    /// reader.expect_eof()?;
+    ///
+    /// assert_eq!(balls, vec!["red", "blue", "green"]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn expect_many<T>(
+        &mut self,
+        name: &str,
+        f: impl FnMut(ElementReader<'a, '_>) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error>;
 }
 
 impl<'a> Expect<'a> for Reader<&'a [u8]> {
     fn expect_element(&mut self, name: &str) -> Result<ElementReader<'a, '_>, Error> {
         log::debug!("expecting element <{name}>");
-        match self.read_event()? {
+        // Peek at the next event on a clone, so that a non-matching event is
+        // left unconsumed for the caller (e.g. a containing `while let`
+        // loop over repeated siblings).
+        let mut probe = self.clone();
+        match probe.read_event()? {
             Event::Start(tag) if tag.name().as_ref() == name.as_bytes() => {
-                log::debug!("found element <{name}>, scanning for end tag");
-                let end = tag.to_end();
-                let span = self.read_text(end.name())?;
-                log::debug!("found matching end tag, decoding contents");
-                log::trace!("got contents {span}");
+                *self = probe;
+                log::debug!("found element <{name}>, matched start-tag");
+                let attrs = collect_attributes(&tag)?;
                 Ok(ElementReader {
-                    _parent: self,
-                    span,
+                    parent: self,
+                    name: tag.name().as_ref().to_vec(),
+                    attrs,
+                    _input: std::marker::PhantomData,
                 })
             }
             Event::Eof => Err(Error::Eof),
@@ -181,8 +282,15 @@ impl<'a> Expect<'a> for Reader<&'a [u8]> {
 
     fn expect_empty(&mut self, name: &str) -> Result<(), Error> {
         log::debug!("expecting element <{name}/>");
-        match self.read_event()? {
-            Event::Empty(tag) if tag.name().as_ref() == name.as_bytes() => Ok(()),
+        // Peek at the next event on a clone, so that a non-matching event is
+        // left unconsumed for the caller (e.g. a containing `while let`
+        // loop over repeated siblings).
+        let mut probe = self.clone();
+        match probe.read_event()? {
+            Event::Empty(tag) if tag.name().as_ref() == name.as_bytes() => {
+                *self = probe;
+                Ok(())
+            }
             Event::Eof => Err(Error::Eof),
             event => Err(Error::unexpected_event(event)),
         }
@@ -198,49 +306,394 @@ impl<'a> Expect<'a> for Reader<&'a [u8]> {
 
     fn expect_text(&mut self) -> Result<Cow<'a, str>, Error> {
         log::debug!("expecting text node");
-        match self.read_event()? {
-            Event::Text(txt) => Ok(txt.unescape()?),
+        let mut content: Option<Cow<'a, str>> = None;
+        loop {
+            // Peek at the next event on a clone, so that the first event
+            // that isn't `Text`/`CData` is left unconsumed for the caller.
+            let mut probe = self.clone();
+            match probe.read_event()? {
+                Event::Text(txt) => {
+                    *self = probe;
+                    append(&mut content, txt.unescape()?);
+                }
+                Event::CData(cdata) => {
+                    *self = probe;
+                    let decoded = self.decoder().decode(cdata.as_ref())?.into_owned();
+                    append(&mut content, Cow::Owned(decoded));
+                }
+                Event::Eof if content.is_none() => return Err(Error::Eof),
+                event if content.is_none() => return Err(Error::unexpected_event(event)),
+                _ => break,
+            }
+        }
+        Ok(content.unwrap_or_default())
+    }
+
+    fn expect_any<'n>(
+        &mut self,
+        names: &[&'n str],
+    ) -> Result<(&'n str, ElementReader<'a, '_>), Error> {
+        log::debug!("expecting one of {names:?}");
+        // Peek at the next event on a clone, so that a non-matching event is
+        // left unconsumed for the caller (e.g. a containing `while let`
+        // loop over repeated siblings).
+        let mut probe = self.clone();
+        match probe.read_event()? {
+            Event::Start(tag) => {
+                match names
+                    .iter()
+                    .find(|candidate| tag.name().as_ref() == candidate.as_bytes())
+                {
+                    Some(&matched) => {
+                        *self = probe;
+                        log::debug!("found element <{matched}>, matched start-tag");
+                        let attrs = collect_attributes(&tag)?;
+                        Ok((
+                            matched,
+                            ElementReader {
+                                parent: self,
+                                name: tag.name().as_ref().to_vec(),
+                                attrs,
+                                _input: std::marker::PhantomData,
+                            },
+                        ))
+                    }
+                    None => Err(Error::unexpected_event(Event::Start(tag))),
+                }
+            }
             Event::Eof => Err(Error::Eof),
             event => Err(Error::unexpected_event(event)),
         }
     }
+
+    fn expect_many<T>(
+        &mut self,
+        name: &str,
+        mut f: impl FnMut(ElementReader<'a, '_>) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        let mut matched = Vec::new();
+        loop {
+            // Peek at the next event on a clone, so that the event that
+            // ends the run of `name` siblings is left unconsumed.
+            let mut probe = self.clone();
+            match probe.read_event()? {
+                Event::Start(tag) if tag.name().as_ref() == name.as_bytes() => {
+                    *self = probe;
+                    let attrs = collect_attributes(&tag)?;
+                    let element = ElementReader {
+                        parent: self,
+                        name: tag.name().as_ref().to_vec(),
+                        attrs,
+                        _input: std::marker::PhantomData,
+                    };
+                    matched.push(f(element)?);
+                }
+                _ => break,
+            }
+        }
+        Ok(matched)
+    }
+}
+
+/// Append `chunk` to `content`, coalescing consecutive `Text`/`CData` runs
+/// into a single owned string once more than one chunk is seen.
+fn append<'a>(content: &mut Option<Cow<'a, str>>, chunk: Cow<'a, str>) {
+    *content = Some(match content.take() {
+        None => chunk,
+        Some(existing) => Cow::Owned(existing.into_owned() + &chunk),
+    });
 }
 
 /// An object providing access to the inner content of a non-leaf XML node,
-/// returned by [`Expect::expect_element()`].
-pub struct ElementReader<'a, 'b> {
-    _parent: &'b mut Reader<&'a [u8]>,
-    span: Cow<'a, str>,
+/// returned by [`Expect::expect_element()`] or [`NsExpect::expect_element_ns()`].
+///
+/// The `R` type parameter tracks which reader type matched the element, so
+/// that [`ElementReader::read_inner()`] can hand the closure a reader of the
+/// same kind.
+pub struct ElementReader<'a, 'b, R = Reader<&'a [u8]>> {
+    parent: &'b mut R,
+    name: Vec<u8>,
+    attrs: Vec<(String, String)>,
+    // The attribute values above are always owned: `BytesStart::attributes()`
+    // borrows from the tag itself, not from the original `'a` input, so they
+    // can't be stored as `Cow<'a, str>`. Keep `'a` around via this marker, as
+    // it's still the lifetime threaded through the default `R` parameter and
+    // the `read_inner()` impls below.
+    _input: std::marker::PhantomData<&'a [u8]>,
 }
 
-impl ElementReader<'_, '_> {
-    /// Consume the contents of `self` using a [`quick_xml::Reader`].
+impl<R> ElementReader<'_, '_, R> {
+    /// Look up the unescaped value of the matched start-tag's `key`
+    /// attribute, if present.
+    ///
+    /// Returns `&str` rather than `Cow<str>`: the attribute values are
+    /// always owned `String`s (see the field comment on [`ElementReader`]),
+    /// so a `Cow` here would never actually borrow.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use xmhell::{Error, Expect, quick_xml::Reader};
+    /// let name = Reader::from_str(r#"<project name="project-name"></project>"#)
+    ///     .expect_element("project")?
+    ///     .attribute("name")
+    ///     .map(str::to_string);
+    ///
+    /// assert_eq!(name, Some("project-name".to_string()));
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[must_use]
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Look up the unescaped value of the matched start-tag's `key`
+    /// attribute.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::MissingAttribute`] is returned if `key` is not present on
+    /// the matched start-tag.
+    pub fn try_attribute(&self, key: &str) -> Result<&str, Error> {
+        self.attribute(key)
+            .ok_or_else(|| Error::MissingAttribute(key.to_string()))
+    }
+
+    /// Iterate over the matched start-tag's attributes as unescaped
+    /// key/value pairs.
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attrs.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+impl<'a> ElementReader<'a, '_, Reader<&'a [u8]>> {
+    /// Consume the contents of `self` using a [`quick_xml::Reader`]
+    /// positioned directly on the parent reader.
+    ///
+    /// Unlike materializing and re-parsing the element's inner span, `f` is
+    /// handed the very same reader that matched the element, so borrowed
+    /// [`Cow::Borrowed`] text stays borrowed from the original input. Once
+    /// `f` returns, `self` is fast-forwarded past the matching end-tag by
+    /// tracking nesting depth, the way [`quick_xml`]'s own
+    /// `read_to_end_into` does.
     ///
     /// See [`Expect`] for usage examples.
     ///
     /// # Errors
     ///
-    /// An error is returned if the closure `f` returns an error, or if the
-    /// [`quick_xml::Reader`] is not fully consumed by `f`.
+    /// An error is returned if the closure `f` returns an error, or if
+    /// `self` reaches end-of-file before the matching end-tag is found.
     pub fn read_inner<F, T>(self, mut f: F) -> Result<T, Error>
     where
         F: FnMut(
-            &mut Reader<&[u8]>,
+            &mut Reader<&'a [u8]>,
         ) -> Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>,
     {
-        let slice = self.span.as_ref();
-        let mut reader = Reader::from_str(slice);
-        _ = reader.trim_text(true);
-        let result = f(&mut reader)?;
-        reader.expect_eof()?;
+        let result = f(self.parent)?;
+        skip_to_end(self.parent, &self.name)?;
         Ok(result)
     }
 }
 
-impl fmt::Debug for ElementReader<'_, '_> {
+impl<'a> ElementReader<'a, '_, NsReader<&'a [u8]>> {
+    /// Consume the contents of `self` using a [`quick_xml::NsReader`]
+    /// positioned directly on the parent reader.
+    ///
+    /// Behaves exactly like [`ElementReader::read_inner()`], but preserves
+    /// namespace resolution for the closure so that matching nested,
+    /// namespaced elements via [`NsExpect`] keeps working.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the closure `f` returns an error, or if
+    /// `self` reaches end-of-file before the matching end-tag is found.
+    pub fn read_inner<F, T>(self, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut(
+            &mut NsReader<&'a [u8]>,
+        ) -> Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        let result = f(self.parent)?;
+        skip_to_end_ns(self.parent, &self.name)?;
+        Ok(result)
+    }
+}
+
+impl<R> fmt::Debug for ElementReader<'_, '_, R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ElementReader")
-            .field("span", &self.span)
+            .field("name", &String::from_utf8_lossy(&self.name))
+            .field("attrs", &self.attrs)
             .finish()
     }
 }
+
+/// Fast-forward `reader` past the end-tag matching `name`, tracking nesting
+/// depth so that nested elements sharing `name` don't terminate the skip
+/// early.
+fn skip_to_end(reader: &mut Reader<&[u8]>, name: &[u8]) -> Result<(), Error> {
+    let mut depth = 0u32;
+    loop {
+        match reader.read_event()? {
+            Event::Start(tag) if tag.name().as_ref() == name => depth += 1,
+            Event::End(tag) if tag.name().as_ref() == name => {
+                if depth == 0 {
+                    return Ok(());
+                }
+                depth -= 1;
+            }
+            Event::Eof => return Err(Error::Eof),
+            _ => {}
+        }
+    }
+}
+
+/// As [`skip_to_end()`], but reading via a [`quick_xml::NsReader`].
+fn skip_to_end_ns(reader: &mut NsReader<&[u8]>, name: &[u8]) -> Result<(), Error> {
+    let mut depth = 0u32;
+    loop {
+        match reader.read_event()? {
+            Event::Start(tag) if tag.name().as_ref() == name => depth += 1,
+            Event::End(tag) if tag.name().as_ref() == name => {
+                if depth == 0 {
+                    return Ok(());
+                }
+                depth -= 1;
+            }
+            Event::Eof => return Err(Error::Eof),
+            _ => {}
+        }
+    }
+}
+
+/// Namespace-aware counterpart of [`Expect`], backed by a
+/// [`quick_xml::NsReader`].
+///
+/// Where [`Expect`] matches on the raw, possibly-prefixed tag name, these
+/// methods resolve the tag's namespace via [`NsReader::read_resolved_event()`]
+/// and match on the resolved namespace URI together with the tag's local
+/// name. This allows traversing documents where elements are qualified by a
+/// namespace prefix (or a default namespace) without having to resolve
+/// prefixes by hand.
+///
+/// # Examples
+///
+/// ``` rust
+/// # use xmhell::{Error, NsExpect, quick_xml::reader::NsReader};
+/// let input = r#"<p:root xmlns:p="urn:example"><p:leaf/></p:root>"#;
+///
+/// let mut reader = NsReader::from_reader(input.as_bytes());
+/// _ = reader.trim_text(true);
+///
+/// reader.expect_element_ns("urn:example", "root")?.read_inner(|reader| {
+///     reader.expect_empty_ns("urn:example", "leaf")?;
+///     Ok(())
+/// })?;
+/// reader.expect_eof()?;
+/// # Ok::<(), Error>(())
+/// ```
+pub trait NsExpect<'a>: Sealed {
+    /// Attempt to match and consume a span `<name>...</name>` resolved to
+    /// `namespace`, where `name`'s local part is `local`.
+    ///
+    /// On success an [`ElementReader`] is returned that can be used to read
+    /// the child nodes of the matched element.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::Eof`] is returned if `self` reaches the end of it's input.
+    /// This is useful to signal a containing loop to `break`.
+    /// Otherwise, an [`Error::UnexpectedEvent`] is returned if the next
+    /// [`Event`] encountered is not a start-tag resolving to `namespace` and
+    /// `local`.
+    ///
+    /// An [`Error::Reader`] is returned if an error is encountered while
+    /// trying to read from `self`.
+    fn expect_element_ns(
+        &mut self,
+        namespace: &str,
+        local: &str,
+    ) -> Result<ElementReader<'a, '_, NsReader<&'a [u8]>>, Error>;
+
+    /// Attempt to match and consume an empty element `<name/>` resolved to
+    /// `namespace`, where `name`'s local part is `local`.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::Eof`] is returned if `self` reaches the end of it's input.
+    /// This is useful to signal a containing loop to `break`.
+    /// Otherwise, an [`Error::UnexpectedEvent`] is returned if the next
+    /// [`Event`] encountered is not an empty-tag resolving to `namespace`
+    /// and `local`.
+    ///
+    /// An [`Error::Reader`] is returned if an error is encountered while
+    /// trying to read from `self`.
+    fn expect_empty_ns(&mut self, namespace: &str, local: &str) -> Result<(), Error>;
+
+    /// Attempt to match and consume an [`Event::Eof`].
+    ///
+    /// [`NsReader`] doesn't implement `DerefMut` to the underlying
+    /// [`quick_xml::Reader`], so [`Expect::expect_eof()`] isn't reachable
+    /// through it; this mirrors that method directly.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error::UnexpectedEvent`] is returned if `self` is not at the end
+    /// of its input.
+    ///
+    /// An [`Error::Reader`] is returned if an error is encountered while
+    /// trying to read from `self`.
+    fn expect_eof(&mut self) -> Result<(), Error>;
+}
+
+impl<'a> NsExpect<'a> for NsReader<&'a [u8]> {
+    fn expect_element_ns(
+        &mut self,
+        namespace: &str,
+        local: &str,
+    ) -> Result<ElementReader<'a, '_, Self>, Error> {
+        log::debug!("expecting element <{{{namespace}}}{local}>");
+        match self.read_resolved_event()? {
+            (ResolveResult::Bound(ns), Event::Start(tag))
+                if ns.as_ref() == namespace.as_bytes()
+                    && tag.local_name().as_ref() == local.as_bytes() =>
+            {
+                log::debug!("found element <{{{namespace}}}{local}>, matched start-tag");
+                let attrs = collect_attributes(&tag)?;
+                Ok(ElementReader {
+                    parent: self,
+                    name: tag.name().as_ref().to_vec(),
+                    attrs,
+                    _input: std::marker::PhantomData,
+                })
+            }
+            (_, Event::Eof) => Err(Error::Eof),
+            (_, event) => Err(Error::unexpected_event(event)),
+        }
+    }
+
+    fn expect_empty_ns(&mut self, namespace: &str, local: &str) -> Result<(), Error> {
+        log::debug!("expecting element <{{{namespace}}}{local}/>");
+        match self.read_resolved_event()? {
+            (ResolveResult::Bound(ns), Event::Empty(tag))
+                if ns.as_ref() == namespace.as_bytes()
+                    && tag.local_name().as_ref() == local.as_bytes() =>
+            {
+                Ok(())
+            }
+            (_, Event::Eof) => Err(Error::Eof),
+            (_, event) => Err(Error::unexpected_event(event)),
+        }
+    }
+
+    fn expect_eof(&mut self) -> Result<(), Error> {
+        log::debug!("expecting end-of-file");
+        match self.read_event()? {
+            Event::Eof => Ok(()),
+            event => Err(Error::unexpected_event(event)),
+        }
+    }
+}
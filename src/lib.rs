@@ -49,6 +49,9 @@
 #![warn(clippy::cargo)]
 #![warn(clippy::nursery)]
 #![allow(clippy::redundant_pub_crate)]
+// `AsyncElementReader::read_inner()`'s closure is always awaited locally,
+// never spawned onto another task, so it doesn't need to be `Send`.
+#![cfg_attr(feature = "async", allow(clippy::future_not_send))]
 // rustc lints
 #![allow(box_pointers)]
 #![warn(absolute_paths_not_starting_with_crate)]
@@ -89,11 +92,16 @@
 pub use quick_xml;
 
 mod expect;
-pub use self::expect::{ElementReader, Expect};
+pub use self::expect::{ElementReader, Expect, NsExpect};
 
 mod error;
 pub use self::error::Error;
 
+#[cfg(feature = "async")]
+mod async_expect;
+#[cfg(feature = "async")]
+pub use self::async_expect::{AsyncElementReader, AsyncExpect};
+
 // silence unused dev-dependency warnings
 #[cfg(test)]
 mod deps {